@@ -168,6 +168,563 @@ fn tan(n: f64, degrees: bool) -> f64 {
     }
 }
 
+/// Finds the Arcsine (inverse sine) of a number, with an optional `degrees` argument to
+/// convert the result (default is radians).
+///
+/// Values outside `[-1, 1]` are outside the domain of arcsine and return `NaN`, matching
+/// the behaviour of `f64::asin`.
+///
+/// # Arguments
+/// * `n` - Input ratio, expected to be in `[-1, 1]`.
+/// * `degrees` - Set to `true` to return the result in degrees (optional, default `false`).
+///
+/// # Example
+/// ```python
+/// mathrs.asin(1.0)  # Returns ~1.5708 (π/2)
+/// mathrs.asin(1.0, degrees=True)  # Returns 90.0
+/// ```
+#[pyfunction]
+#[pyo3(signature = (n, degrees=false))]
+fn asin(n: f64, degrees: bool) -> f64 {
+    let result = n.asin();
+
+    if degrees {
+        result.to_degrees()
+    } else {
+        result
+    }
+}
+
+/// Finds the Arccosine (inverse cosine) of a number, with an optional `degrees` argument to
+/// convert the result (default is radians).
+///
+/// Values outside `[-1, 1]` are outside the domain of arccosine and return `NaN`, matching
+/// the behaviour of `f64::acos`.
+///
+/// # Arguments
+/// * `n` - Input ratio, expected to be in `[-1, 1]`.
+/// * `degrees` - Set to `true` to return the result in degrees (optional, default `false`).
+///
+/// # Example
+/// ```python
+/// mathrs.acos(1.0)  # Returns 0.0
+/// mathrs.acos(0.0, degrees=True)  # Returns 90.0
+/// ```
+#[pyfunction]
+#[pyo3(signature = (n, degrees=false))]
+fn acos(n: f64, degrees: bool) -> f64 {
+    let result = n.acos();
+
+    if degrees {
+        result.to_degrees()
+    } else {
+        result
+    }
+}
+
+/// Finds the Arctangent (inverse tangent) of a number, with an optional `degrees` argument to
+/// convert the result (default is radians).
+///
+/// # Arguments
+/// * `n` - Input number.
+/// * `degrees` - Set to `true` to return the result in degrees (optional, default `false`).
+///
+/// # Example
+/// ```python
+/// mathrs.atan(1.0)  # Returns ~0.7854 (π/4)
+/// mathrs.atan(1.0, degrees=True)  # Returns 45.0
+/// ```
+#[pyfunction]
+#[pyo3(signature = (n, degrees=false))]
+fn atan(n: f64, degrees: bool) -> f64 {
+    let result = n.atan();
+
+    if degrees {
+        result.to_degrees()
+    } else {
+        result
+    }
+}
+
+/// Finds the two-argument Arctangent of `y` and `x`, i.e. the angle of the point `(x, y)`
+/// relative to the positive x-axis, with an optional `degrees` argument to convert the result
+/// (default is radians).
+///
+/// Unlike `atan(y / x)`, this correctly determines the quadrant of the angle and handles
+/// `x == 0.0` without dividing by zero.
+///
+/// # Arguments
+/// * `y` - The y-coordinate.
+/// * `x` - The x-coordinate.
+/// * `degrees` - Set to `true` to return the result in degrees (optional, default `false`).
+///
+/// # Example
+/// ```python
+/// mathrs.atan2(1.0, 1.0)  # Returns ~0.7854 (π/4)
+/// mathrs.atan2(1.0, 0.0, degrees=True)  # Returns 90.0
+/// ```
+#[pyfunction]
+#[pyo3(signature = (y, x, degrees=false))]
+fn atan2(y: f64, x: f64, degrees: bool) -> f64 {
+    let result = y.atan2(x);
+
+    if degrees {
+        result.to_degrees()
+    } else {
+        result
+    }
+}
+
+/// Finds the Hyperbolic Sine of a number.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.sinh(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn sinh(n: f64) -> f64 {
+    n.sinh()
+}
+
+/// Finds the Hyperbolic Cosine of a number.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.cosh(0)  # Returns 1.0
+/// ```
+#[pyfunction]
+fn cosh(n: f64) -> f64 {
+    n.cosh()
+}
+
+/// Finds the Hyperbolic Tangent of a number.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.tanh(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn tanh(n: f64) -> f64 {
+    n.tanh()
+}
+
+/// Finds the Inverse Hyperbolic Sine of a number.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.asinh(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn asinh(n: f64) -> f64 {
+    n.asinh()
+}
+
+/// Finds the Inverse Hyperbolic Cosine of a number.
+///
+/// Values below `1.0` are outside the domain of arcosh and return `NaN`, matching the
+/// behaviour of `f64::acosh`.
+///
+/// # Arguments
+/// * `n` - Input number, expected to be `>= 1.0`.
+///
+/// # Example
+/// ```python
+/// mathrs.acosh(1)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn acosh(n: f64) -> f64 {
+    n.acosh()
+}
+
+/// Finds the Inverse Hyperbolic Tangent of a number.
+///
+/// Values outside `(-1, 1)` are outside the domain of artanh and return `NaN` or `±inf`,
+/// matching the behaviour of `f64::atanh`.
+///
+/// # Arguments
+/// * `n` - Input number, expected to be in `(-1, 1)`.
+///
+/// # Example
+/// ```python
+/// mathrs.atanh(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn atanh(n: f64) -> f64 {
+    n.atanh()
+}
+
+/// Computes `ln(1 + n)` accurately even when `n` is very small.
+///
+/// Naively computing `(1.0 + n).ln()` loses almost all precision for small `n` because
+/// `1.0 + n` rounds back to `1.0` before the logarithm is even taken; `f64::ln_1p` avoids
+/// that rounding.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.log1p(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn log1p(n: f64) -> f64 {
+    n.ln_1p()
+}
+
+/// Computes `exp(n) - 1` accurately even when `n` is very small.
+///
+/// Naively computing `n.exp() - 1.0` loses almost all precision for small `n` for the same
+/// reason `log1p` is needed; `f64::exp_m1` avoids that cancellation.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.expm1(0)  # Returns 0.0
+/// ```
+#[pyfunction]
+fn expm1(n: f64) -> f64 {
+    n.exp_m1()
+}
+
+/// Returns `x` with the sign of `y`, including correct handling of signed zero and `±inf`.
+///
+/// # Arguments
+/// * `x` - The number whose magnitude is kept.
+/// * `y` - The number whose sign is copied.
+///
+/// # Example
+/// ```python
+/// mathrs.copysign(3.0, -1.0)  # Returns -3.0
+/// mathrs.copysign(-3.0, 1.0)  # Returns 3.0
+/// ```
+#[pyfunction]
+fn copysign(x: f64, y: f64) -> f64 {
+    x.copysign(y)
+}
+
+/// Computes `sqrt(x^2 + y^2)` without the overflow/underflow that naively squaring `x` and
+/// `y` causes for very large or very small inputs.
+///
+/// Uses the classic scaled algorithm: the larger magnitude is factored out so the squared
+/// ratio stays in `[0, 1]`, which can never overflow even when `x` or `y` is close to
+/// `f64::MAX`.
+///
+/// # Arguments
+/// * `x` - First leg.
+/// * `y` - Second leg.
+///
+/// # Example
+/// ```python
+/// mathrs.hypot(3.0, 4.0)  # Returns 5.0
+/// ```
+#[pyfunction]
+fn hypot(x: f64, y: f64) -> f64 {
+    let mut ax = x.abs();
+    let mut ay = y.abs();
+
+    if ax < ay {
+        std::mem::swap(&mut ax, &mut ay);
+    }
+
+    if ax == 0.0 {
+        return 0.0;
+    }
+
+    let r = ay / ax;
+    ax * (1.0 + r * r).sqrt()
+}
+
+/// Rounds a number down to the nearest integer.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.floor(1.7)  # Returns 1.0
+/// ```
+#[pyfunction]
+fn floor(n: f64) -> f64 {
+    n.floor()
+}
+
+/// Rounds a number up to the nearest integer.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.ceil(1.2)  # Returns 2.0
+/// ```
+#[pyfunction]
+fn ceil(n: f64) -> f64 {
+    n.ceil()
+}
+
+/// Rounds a number to the nearest integer, with ties rounding away from zero.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.round(1.5)  # Returns 2.0
+/// ```
+#[pyfunction]
+fn round(n: f64) -> f64 {
+    n.round()
+}
+
+/// Truncates a number towards zero, discarding its fractional part.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.trunc(1.7)  # Returns 1.0
+/// ```
+#[pyfunction]
+fn trunc(n: f64) -> f64 {
+    n.trunc()
+}
+
+/// Returns the fractional part of a number.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.fract(1.7)  # Returns ~0.7
+/// ```
+#[pyfunction]
+fn fract(n: f64) -> f64 {
+    n.fract()
+}
+
+/// Returns the sign of a number as `1.0`, `-1.0`, or `NaN` if the input is `NaN`.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.signum(-3.5)  # Returns -1.0
+/// ```
+#[pyfunction]
+fn signum(n: f64) -> f64 {
+    n.signum()
+}
+
+/// Checks whether a number is `NaN` (Not a Number).
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.is_nan(float('nan'))  # Returns True
+/// ```
+#[pyfunction]
+fn is_nan(n: f64) -> bool {
+    n.is_nan()
+}
+
+/// Checks whether a number is infinite.
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.is_infinite(float('inf'))  # Returns True
+/// ```
+#[pyfunction]
+fn is_infinite(n: f64) -> bool {
+    n.is_infinite()
+}
+
+/// Checks whether a number is finite (neither infinite nor `NaN`).
+///
+/// # Arguments
+/// * `n` - Input number.
+///
+/// # Example
+/// ```python
+/// mathrs.is_finite(1.0)  # Returns True
+/// ```
+#[pyfunction]
+fn is_finite(n: f64) -> bool {
+    n.is_finite()
+}
+
+/// A complex number, analogous to Python's built-in `complex` / CPython's `cmath` module.
+///
+/// # Example
+/// ```python
+/// z = mathrs.Complex(3.0, 4.0)
+/// z.re  # Returns 3.0
+/// z.im  # Returns 4.0
+/// ```
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    #[pyo3(get, set)]
+    re: f64,
+    #[pyo3(get, set)]
+    im: f64,
+}
+
+#[pymethods]
+impl Complex {
+    #[new]
+    fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Complex(re={}, im={})", self.re, self.im)
+    }
+}
+
+/// Adds two complex numbers.
+///
+/// # Arguments
+/// * `a` - First operand.
+/// * `b` - Second operand.
+///
+/// # Example
+/// ```python
+/// mathrs.cadd(mathrs.Complex(1.0, 2.0), mathrs.Complex(3.0, 4.0))  # Returns Complex(re=4.0, im=6.0)
+/// ```
+#[pyfunction]
+fn cadd(a: &Complex, b: &Complex) -> Complex {
+    Complex::new(a.re + b.re, a.im + b.im)
+}
+
+/// Multiplies two complex numbers.
+///
+/// # Arguments
+/// * `a` - First operand.
+/// * `b` - Second operand.
+///
+/// # Example
+/// ```python
+/// mathrs.cmul(mathrs.Complex(1.0, 2.0), mathrs.Complex(3.0, 4.0))  # Returns Complex(re=-5.0, im=10.0)
+/// ```
+#[pyfunction]
+fn cmul(a: &Complex, b: &Complex) -> Complex {
+    Complex::new(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re)
+}
+
+/// Divides two complex numbers (`a / b`).
+///
+/// # Arguments
+/// * `a` - Numerator.
+/// * `b` - Denominator.
+///
+/// # Example
+/// ```python
+/// mathrs.cdiv(mathrs.Complex(1.0, 2.0), mathrs.Complex(3.0, 4.0))  # Returns Complex(re=0.44, im=0.08)
+/// ```
+#[pyfunction]
+fn cdiv(a: &Complex, b: &Complex) -> Complex {
+    // Scale by the larger magnitude component first so `denom` can't overflow for
+    // large-magnitude operands, the same way `hypot` avoids squaring unscaled values.
+    if b.re.abs() >= b.im.abs() {
+        let r = b.im / b.re;
+        let denom = b.re + b.im * r;
+        Complex::new((a.re + a.im * r) / denom, (a.im - a.re * r) / denom)
+    } else {
+        let r = b.re / b.im;
+        let denom = b.re * r + b.im;
+        Complex::new((a.re * r + a.im) / denom, (a.im * r - a.re) / denom)
+    }
+}
+
+/// Finds the magnitude (absolute value) of a complex number.
+///
+/// Uses the overflow-safe `hypot` algorithm rather than `sqrt(re^2 + im^2)` directly.
+///
+/// # Arguments
+/// * `z` - Input complex number.
+///
+/// # Example
+/// ```python
+/// mathrs.cabs(mathrs.Complex(3.0, 4.0))  # Returns 5.0
+/// ```
+#[pyfunction]
+fn cabs(z: &Complex) -> f64 {
+    hypot(z.re, z.im)
+}
+
+/// Computes `e^z` for a complex number `z`, using `exp(a+bi) = e^a * (cos(b) + i*sin(b))`.
+///
+/// # Arguments
+/// * `z` - Input complex number.
+///
+/// # Example
+/// ```python
+/// mathrs.cexp(mathrs.Complex(0.0, 0.0))  # Returns Complex(re=1.0, im=0.0)
+/// ```
+#[pyfunction]
+fn cexp(z: &Complex) -> Complex {
+    let r = z.re.exp();
+    Complex::new(r * z.im.cos(), r * z.im.sin())
+}
+
+/// Computes the principal branch of the natural logarithm of a complex number, using
+/// `log(z) = ln|z| + i*atan2(im, re)`.
+///
+/// # Arguments
+/// * `z` - Input complex number.
+///
+/// # Example
+/// ```python
+/// mathrs.clog(mathrs.Complex(1.0, 0.0))  # Returns Complex(re=0.0, im=0.0)
+/// ```
+#[pyfunction]
+fn clog(z: &Complex) -> Complex {
+    Complex::new(cabs(z).ln(), z.im.atan2(z.re))
+}
+
+/// Computes the principal square root of a complex number.
+///
+/// Computed as `sqrt((|z|+re)/2) + i*sign(im)*sqrt((|z|-re)/2)` rather than via `clog`/`cexp`,
+/// which keeps the result numerically stable close to the branch cut on the negative real axis.
+///
+/// # Arguments
+/// * `z` - Input complex number.
+///
+/// # Example
+/// ```python
+/// mathrs.csqrt(mathrs.Complex(-1.0, 0.0))  # Returns Complex(re=0.0, im=1.0)
+/// ```
+#[pyfunction]
+fn csqrt(z: &Complex) -> Complex {
+    let mag = cabs(z);
+    let re = ((mag + z.re) / 2.0).sqrt();
+    let im_sign = if z.im.is_sign_negative() { -1.0 } else { 1.0 };
+    let im = im_sign * ((mag - z.re) / 2.0).sqrt();
+    Complex::new(re, im)
+}
+
 
 
 /// Computes the Rectified Linear Unit (ReLU) of a number.
@@ -210,19 +767,30 @@ fn sigmoid(n: f64) -> f64 {
 
 /// Computes the Softmax activation function of a list of numbers.
 /// Maps a list of real values to the range [0, 1] such that the sum of the values is 1.
-/// 
+///
+/// Uses the max-subtraction trick for numerical stability: subtracting the list's max
+/// before exponentiating keeps every exponent `<= 0` (so it can't overflow) without
+/// changing the result, since the subtracted `max` cancels out of the final ratio.
+///
 /// # Arguments
 /// * `list` - Input list of numbers.
-/// 
+///
 /// # Example
 /// ```python
 /// mathrs.softmax([1, 2, 3])  # Returns [~0.09, ~0.24, ~0.67]
+/// mathrs.softmax([])  # Returns []
 /// ```
 #[pyfunction]
 #[pyo3(signature = (list))]
 fn softmax(list: Vec<f64>) -> Vec<f64> {
-    let sum: f64 = list.iter().map(|x| x.exp()).sum();
-    list.iter().map(|x| x.exp() / sum).collect()
+    if list.is_empty() {
+        return Vec::new();
+    }
+
+    let max = list.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let shifted: Vec<f64> = list.iter().map(|x| (x - max).exp()).collect();
+    let sum: f64 = shifted.iter().sum();
+    shifted.iter().map(|x| x / sum).collect()
 }
 
 /// A Python math module implemented in Rust.
@@ -235,6 +803,37 @@ fn mathrs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sin, m)?)?;
     m.add_function(wrap_pyfunction!(cos, m)?)?;
     m.add_function(wrap_pyfunction!(tan, m)?)?;
+    m.add_function(wrap_pyfunction!(asin, m)?)?;
+    m.add_function(wrap_pyfunction!(acos, m)?)?;
+    m.add_function(wrap_pyfunction!(atan, m)?)?;
+    m.add_function(wrap_pyfunction!(atan2, m)?)?;
+    m.add_function(wrap_pyfunction!(sinh, m)?)?;
+    m.add_function(wrap_pyfunction!(cosh, m)?)?;
+    m.add_function(wrap_pyfunction!(tanh, m)?)?;
+    m.add_function(wrap_pyfunction!(asinh, m)?)?;
+    m.add_function(wrap_pyfunction!(acosh, m)?)?;
+    m.add_function(wrap_pyfunction!(atanh, m)?)?;
+    m.add_function(wrap_pyfunction!(log1p, m)?)?;
+    m.add_function(wrap_pyfunction!(expm1, m)?)?;
+    m.add_function(wrap_pyfunction!(copysign, m)?)?;
+    m.add_function(wrap_pyfunction!(hypot, m)?)?;
+    m.add_function(wrap_pyfunction!(floor, m)?)?;
+    m.add_function(wrap_pyfunction!(ceil, m)?)?;
+    m.add_function(wrap_pyfunction!(round, m)?)?;
+    m.add_function(wrap_pyfunction!(trunc, m)?)?;
+    m.add_function(wrap_pyfunction!(fract, m)?)?;
+    m.add_function(wrap_pyfunction!(signum, m)?)?;
+    m.add_function(wrap_pyfunction!(is_nan, m)?)?;
+    m.add_function(wrap_pyfunction!(is_infinite, m)?)?;
+    m.add_function(wrap_pyfunction!(is_finite, m)?)?;
+    m.add_class::<Complex>()?;
+    m.add_function(wrap_pyfunction!(cadd, m)?)?;
+    m.add_function(wrap_pyfunction!(cmul, m)?)?;
+    m.add_function(wrap_pyfunction!(cdiv, m)?)?;
+    m.add_function(wrap_pyfunction!(cabs, m)?)?;
+    m.add_function(wrap_pyfunction!(cexp, m)?)?;
+    m.add_function(wrap_pyfunction!(clog, m)?)?;
+    m.add_function(wrap_pyfunction!(csqrt, m)?)?;
     m.add_function(wrap_pyfunction!(relu, m)?)?;
     m.add_function(wrap_pyfunction!(sigmoid, m)?)?;
     m.add_function(wrap_pyfunction!(softmax, m)?)?;
@@ -304,6 +903,214 @@ mod tests {
         assert_eq!(tan(270.0, true), f64::NEG_INFINITY);
     }
     
+    #[test]
+    fn test_asin() {
+        assert_eq!(asin(0.0, false), 0.0);
+        assert_eq!(asin(1.0, false), HALF_PI);
+        assert_eq!(asin(1.0, true), 90.0);
+        assert!(asin(2.0, false).is_nan());
+    }
+
+    #[test]
+    fn test_acos() {
+        assert_eq!(acos(1.0, false), 0.0);
+        assert_eq!(acos(0.0, false), HALF_PI);
+        assert_eq!(acos(0.0, true), 90.0);
+        assert!(acos(2.0, false).is_nan());
+    }
+
+    #[test]
+    fn test_atan() {
+        assert_eq!(atan(0.0, false), 0.0);
+        assert_eq!(atan(1.0, false), std::f64::consts::FRAC_PI_4);
+        assert_eq!(atan(1.0, true), 45.0);
+    }
+
+    #[test]
+    fn test_atan2() {
+        assert_eq!(atan2(0.0, 1.0, false), 0.0);
+        assert_eq!(atan2(1.0, 0.0, false), HALF_PI);
+        assert_eq!(atan2(1.0, 0.0, true), 90.0);
+    }
+
+    #[test]
+    fn test_sinh() {
+        assert_eq!(sinh(0.0), 0.0);
+        assert!((sinh(1.0) - 1.1752012).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosh() {
+        assert_eq!(cosh(0.0), 1.0);
+        assert!((cosh(1.0) - 1.5430806).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_tanh() {
+        assert_eq!(tanh(0.0), 0.0);
+        assert!((tanh(1.0) - 0.7615942).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_asinh() {
+        assert_eq!(asinh(0.0), 0.0);
+        assert!((asinh(1.1752012) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_acosh() {
+        assert_eq!(acosh(1.0), 0.0);
+        assert!(acosh(0.0).is_nan());
+    }
+
+    #[test]
+    fn test_atanh() {
+        assert_eq!(atanh(0.0), 0.0);
+        assert!((atanh(0.7615942) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_log1p() {
+        assert_eq!(log1p(0.0), 0.0);
+        assert!((log1p(1e-15) - 1e-15).abs() < 1e-20);
+    }
+
+    #[test]
+    fn test_expm1() {
+        assert_eq!(expm1(0.0), 0.0);
+        assert!((expm1(1e-15) - 1e-15).abs() < 1e-20);
+    }
+
+    #[test]
+    fn test_copysign() {
+        assert_eq!(copysign(3.0, -1.0), -3.0);
+        assert_eq!(copysign(-3.0, 1.0), 3.0);
+    }
+
+    #[test]
+    fn test_hypot() {
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+        assert_eq!(hypot(0.0, 0.0), 0.0);
+        // Naive sqrt(x*x + y*y) would overflow to inf here.
+        assert!((hypot(1e200, 1e200) - 1e200 * 2.0_f64.sqrt()).abs() / 1e200 < 1e-10);
+    }
+
+    #[test]
+    fn test_floor() {
+        assert_eq!(floor(1.7), 1.0);
+        assert_eq!(floor(-1.2), -2.0);
+    }
+
+    #[test]
+    fn test_ceil() {
+        assert_eq!(ceil(1.2), 2.0);
+        assert_eq!(ceil(-1.7), -1.0);
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(round(1.5), 2.0);
+        assert_eq!(round(-1.5), -2.0);
+    }
+
+    #[test]
+    fn test_trunc() {
+        assert_eq!(trunc(1.7), 1.0);
+        assert_eq!(trunc(-1.7), -1.0);
+    }
+
+    #[test]
+    fn test_fract() {
+        assert!((fract(1.7) - 0.7).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!(signum(-3.5), -1.0);
+        assert_eq!(signum(3.5), 1.0);
+    }
+
+    #[test]
+    fn test_is_nan() {
+        assert!(is_nan(f64::NAN));
+        assert!(!is_nan(1.0));
+    }
+
+    #[test]
+    fn test_is_infinite() {
+        assert!(is_infinite(f64::INFINITY));
+        assert!(!is_infinite(1.0));
+    }
+
+    #[test]
+    fn test_is_finite() {
+        assert!(is_finite(1.0));
+        assert!(!is_finite(f64::INFINITY));
+        assert!(!is_finite(f64::NAN));
+    }
+
+    #[test]
+    fn test_cadd() {
+        let result = cadd(&Complex::new(1.0, 2.0), &Complex::new(3.0, 4.0));
+        assert_eq!((result.re, result.im), (4.0, 6.0));
+    }
+
+    #[test]
+    fn test_cmul() {
+        let result = cmul(&Complex::new(1.0, 2.0), &Complex::new(3.0, 4.0));
+        assert_eq!((result.re, result.im), (-5.0, 10.0));
+    }
+
+    #[test]
+    fn test_cdiv() {
+        let result = cdiv(&Complex::new(1.0, 2.0), &Complex::new(3.0, 4.0));
+        assert!((result.re - 0.44).abs() < 1e-10);
+        assert!((result.im - 0.08).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cdiv_large_magnitude() {
+        // Naively squaring these would overflow to inf, turning 1.0 into NaN.
+        let big = Complex::new(1e200, 1e200);
+        let result = cdiv(&big, &big);
+        assert!((result.re - 1.0).abs() < 1e-10);
+        assert!(result.im.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cabs() {
+        assert_eq!(cabs(&Complex::new(3.0, 4.0)), 5.0);
+    }
+
+    #[test]
+    fn test_cexp() {
+        let result = cexp(&Complex::new(0.0, 0.0));
+        assert!((result.re - 1.0).abs() < 1e-10);
+        assert!(result.im.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_clog() {
+        let result = clog(&Complex::new(1.0, 0.0));
+        assert!(result.re.abs() < 1e-10);
+        assert!(result.im.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_csqrt() {
+        let result = csqrt(&Complex::new(-1.0, 0.0));
+        assert!(result.re.abs() < 1e-10);
+        assert!((result.im - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_csqrt_negative_zero_im() {
+        // A negative-zero imaginary part should take the lower branch, not the upper one.
+        let result = csqrt(&Complex::new(-4.0, -0.0));
+        assert!(result.re.abs() < 1e-10);
+        assert!((result.im - (-2.0)).abs() < 1e-10);
+    }
+
     #[test]
     fn test_relu() {
         assert_eq!(relu(3.5), 3.5);
@@ -327,4 +1134,18 @@ mod tests {
             assert!((r - e).abs() < 1e-6);
         }
     }
+
+    #[test]
+    fn test_softmax_empty() {
+        assert_eq!(softmax(vec![]), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_softmax_large_values() {
+        // Naively exponentiating these would overflow to inf/NaN.
+        let scores = vec![1000.0, 1001.0];
+        let result = softmax(scores);
+        assert!(result.iter().all(|r| r.is_finite()));
+        assert!((result.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+    }
 }